@@ -0,0 +1,45 @@
+use std::fmt;
+
+/// Errors that can occur while reading or writing binary data.
+#[derive(Debug)]
+pub enum BinaryError {
+    /// Attempted to read past the end of the stream.
+    ReadPastEof,
+    /// A `u32` did not correspond to a valid `char`.
+    InvalidChar,
+    /// A seek resolved to a position before the start or past the end of the stream.
+    SeekOutOfRange,
+    /// A write would have run past the end of a fixed-capacity buffer.
+    WritePastEnd,
+    /// The underlying stream reported an I/O error.
+    Io(std::io::Error),
+    /// The stream did not contain valid UTF-8.
+    Utf8(std::string::FromUtf8Error),
+}
+
+impl fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ReadPastEof => write!(f, "attempted to read past the end of the stream"),
+            Self::InvalidChar => write!(f, "value is not a valid char"),
+            Self::SeekOutOfRange => write!(f, "seek resolved to a position outside the stream"),
+            Self::WritePastEnd => write!(f, "write would run past the end of a fixed-capacity buffer"),
+            Self::Io(err) => write!(f, "{}", err),
+            Self::Utf8(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for BinaryError {}
+
+impl From<std::io::Error> for BinaryError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<std::string::FromUtf8Error> for BinaryError {
+    fn from(err: std::string::FromUtf8Error) -> Self {
+        Self::Utf8(err)
+    }
+}
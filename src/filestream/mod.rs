@@ -0,0 +1,128 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{Read, Seek, Write},
+    path::Path,
+};
+
+use crate::{resolve_seek, Result, SeekFrom, SeekStream};
+
+/// Controls how a [`FileStream`] opens its backing file.
+pub enum OpenType {
+    /// Open an existing file for reading and writing.
+    Open,
+    /// Open a file for reading and writing, creating it if it does not exist.
+    OpenAndCreate,
+    /// Create the file if it does not exist and truncate it to zero length if it does.
+    Truncate,
+}
+
+/// A [`SeekStream`] backed by a file on disk, so callers can read/write
+/// multi-gigabyte files without loading them into memory.
+pub struct FileStream {
+    file: File,
+}
+
+impl FileStream {
+    /// Open a file at `path` according to `open_type`.
+    pub fn new<P: AsRef<Path>>(path: P, open_type: OpenType) -> Result<Self> {
+        let file = match open_type {
+            OpenType::Open => OpenOptions::new().read(true).write(true).open(path)?,
+            OpenType::OpenAndCreate => OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(path)?,
+            OpenType::Truncate => OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(path)?,
+        };
+        Ok(Self { file })
+    }
+}
+
+/// This implements the `SeekStream` trait from lib.rs into `FileStream`
+impl SeekStream for FileStream {
+    fn seek(&mut self, to: SeekFrom) -> Result<usize> {
+        let position = self.tell()?;
+        let len = self.len()?;
+        let absolute = resolve_seek(to, position, len)?;
+        let new_position = self.file.seek(std::io::SeekFrom::Start(absolute as u64))?;
+        Ok(new_position as usize)
+    }
+
+    fn tell(&mut self) -> Result<usize> {
+        let position = self.file.stream_position()?;
+        Ok(position as usize)
+    }
+
+    fn len(&self) -> Result<usize> {
+        Ok(self.file.metadata()?.len() as usize)
+    }
+}
+
+impl Read for FileStream {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        self.file.read(buffer)
+    }
+}
+
+impl Write for FileStream {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        self.file.write(bytes)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BinaryReader, BinaryWriter, Endian};
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("binary_manager_v2_{}_{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn file_stream_write_then_read() {
+        let path = temp_path("write_then_read");
+
+        {
+            let stream = FileStream::new(&path, OpenType::Truncate).unwrap();
+            let mut writer = BinaryWriter::new_stream(stream, Endian::Little);
+            writer.write_u32(42u32).unwrap();
+        }
+
+        {
+            let stream = FileStream::new(&path, OpenType::Open).unwrap();
+            let mut reader = BinaryReader::new_stream(stream, Endian::Little);
+            assert_eq!(reader.read_u32().unwrap(), 42);
+        }
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn truncate_clears_existing_contents() {
+        let path = temp_path("truncate");
+
+        {
+            let stream = FileStream::new(&path, OpenType::OpenAndCreate).unwrap();
+            let mut writer = BinaryWriter::new_stream(stream, Endian::Little);
+            writer.write_bytes([1, 2, 3, 4]).unwrap();
+        }
+
+        {
+            let stream = FileStream::new(&path, OpenType::Truncate).unwrap();
+            assert_eq!(stream.len().unwrap(), 0);
+        }
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
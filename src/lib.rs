@@ -1,10 +1,14 @@
 use std::{borrow::Borrow, io::Read, io::Write};
 
 mod error;
+mod filestream;
 mod memory;
+mod slicestream;
 
 pub use error::BinaryError;
-pub use memory::memory::MemoryStream;
+pub use filestream::{FileStream, OpenType};
+pub use memory::MemoryStream;
+pub use slicestream::SliceStream;
 
 /// Result type for binary errors.
 pub type Result<T> = std::result::Result<T, BinaryError>;
@@ -15,7 +19,8 @@ macro_rules! write_data {
             Endian::Little => $value.to_le_bytes(),
             Endian::Big => $value.to_be_bytes(),
         };
-        return Ok($stream.write(&data)?);
+        $stream.write_all(&data)?;
+        return Ok(data.len());
     };
 }
 
@@ -29,6 +34,15 @@ macro_rules! read_data {
     };
 }
 
+macro_rules! peek_data {
+    ($self:expr, $read:ident $(, $arg:expr)*) => {{
+        let position = $self.stream.tell()?;
+        let result = $self.$read($($arg),*);
+        $self.stream.seek(SeekFrom::Start(position))?;
+        result
+    }};
+}
+
 /// Variants to describe endianness.
 #[derive(PartialEq)]
 pub enum Endian {
@@ -44,29 +58,50 @@ impl Default for Endian {
     }
 }
 
+//--    SeekFrom      --\\
+/// Describes a seek relative to the start, the current position, or the end
+/// of a [`SeekStream`].
+pub enum SeekFrom {
+    /// Seek to an absolute position from the start of the stream.
+    Start(usize),
+    /// Seek relative to the current position.
+    Current(isize),
+    /// Seek relative to the end of the stream.
+    End(isize),
+}
+
 //--    SeekStream      --\\
 /// Trait for streams that can seek.
 pub trait SeekStream {
-    /// Seek to a position.
-    fn seek(&mut self, to: usize) -> Result<usize>;
+    /// Seek to a position relative to the start, current position, or end of the stream.
+    fn seek(&mut self, to: SeekFrom) -> Result<usize>;
     /// Get the current position.
     fn tell(&mut self) -> Result<usize>;
     /// Get the length of the stream.
     fn len(&self) -> Result<usize>;
 }
 
+/// Resolves a [`SeekFrom`] against the given `tell`/`len` into an absolute position,
+/// erroring if it falls outside the stream.
+pub(crate) fn resolve_seek(to: SeekFrom, position: usize, len: usize) -> Result<usize> {
+    let absolute = match to {
+        SeekFrom::Start(pos) => pos as isize,
+        SeekFrom::Current(offset) => position as isize + offset,
+        SeekFrom::End(offset) => len as isize + offset,
+    };
+    if absolute < 0 || absolute as usize > len {
+        return Err(BinaryError::SeekOutOfRange);
+    }
+    Ok(absolute as usize)
+}
+
 //--        BinaryReader      --\\
-pub struct BinaryReader<'a> {
-    pub stream: MemoryStream<'a>,
+pub struct BinaryReader<S: SeekStream + Read> {
+    pub stream: S,
     endian: Endian,
 }
 
-impl<'a> BinaryReader<'a> {
-    /// Createa a new BinaryWriter with a predefined MemoryStream
-    pub fn new_stream<'b>(stream: MemoryStream<'a>, endian: Endian) -> Self {
-        Self { endian, stream }
-    }
-
+impl<'a> BinaryReader<MemoryStream<'a>> {
     /// Createa a new BinaryWriter with a predefined buffer
     pub fn new_vec(buffer: &'a mut Vec<u8>, endian: Endian) -> Self {
         Self {
@@ -74,18 +109,25 @@ impl<'a> BinaryReader<'a> {
             stream: MemoryStream::new_vec(buffer),
         }
     }
+}
+
+impl<S: SeekStream + Read> BinaryReader<S> {
+    /// Createa a new BinaryReader with a predefined stream
+    pub fn new_stream(stream: S, endian: Endian) -> Self {
+        Self { endian, stream }
+    }
 
     /// Read a length-prefixed `String` from the stream.
     pub fn read_string(&mut self) -> Result<String> {
         let chars = if cfg!(feature = "wasm32") {
             let str_len = self.read_u32()?;
             let mut chars: Vec<u8> = vec![0; str_len as usize];
-            self.stream.read(&mut chars)?;
+            self.stream.read_exact(&mut chars)?;
             chars
         } else {
             let str_len = self.read_usize()?;
             let mut chars: Vec<u8> = vec![0; str_len];
-            self.stream.read(&mut chars)?;
+            self.stream.read_exact(&mut chars)?;
             chars
         };
         Ok(String::from_utf8(chars)?)
@@ -114,14 +156,14 @@ impl<'a> BinaryReader<'a> {
     /// Read a `f32` from the stream.
     pub fn read_f32(&mut self) -> Result<f32> {
         let mut buffer: [u8; 4] = [0; 4];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         read_data!(self.endian, buffer, f32);
     }
 
     /// Read a `f64` from the stream.
     pub fn read_f64(&mut self) -> Result<f64> {
         let mut buffer: [u8; 8] = [0; 8];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         read_data!(self.endian, buffer, f64);
     }
 
@@ -129,7 +171,7 @@ impl<'a> BinaryReader<'a> {
     #[cfg(target_arch = "wasm32")]
     pub fn read_isize(&mut self) -> Result<isize> {
         let mut buffer: [u8; 4] = [0; 4];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         read_data!(self.endian, buffer, isize);
     }
 
@@ -137,7 +179,7 @@ impl<'a> BinaryReader<'a> {
     #[cfg(not(target_arch = "wasm32"))]
     pub fn read_isize(&mut self) -> Result<isize> {
         let mut buffer: [u8; 8] = [0; 8];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         read_data!(self.endian, buffer, isize);
     }
 
@@ -145,7 +187,7 @@ impl<'a> BinaryReader<'a> {
     #[cfg(target_arch = "wasm32")]
     pub fn read_usize(&mut self) -> Result<usize> {
         let mut buffer: [u8; 4] = [0; 4];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         read_data!(self.endian, buffer, usize);
     }
 
@@ -153,78 +195,78 @@ impl<'a> BinaryReader<'a> {
     #[cfg(not(target_arch = "wasm32"))]
     pub fn read_usize(&mut self) -> Result<usize> {
         let mut buffer: [u8; 8] = [0; 8];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         read_data!(self.endian, buffer, usize);
     }
 
     /// Read a `u64` from the stream.
     pub fn read_u64(&mut self) -> Result<u64> {
         let mut buffer: [u8; 8] = [0; 8];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         read_data!(self.endian, buffer, u64);
     }
 
     /// Read an `i64` from the stream.
     pub fn read_i64(&mut self) -> Result<i64> {
         let mut buffer: [u8; 8] = [0; 8];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         read_data!(self.endian, buffer, i64);
     }
 
     /// Read a `u32` from the stream.
     pub fn read_u32(&mut self) -> Result<u32> {
         let mut buffer: [u8; 4] = [0; 4];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         read_data!(self.endian, buffer, u32);
     }
 
     /// Read an `i32` from the stream.
     pub fn read_i32(&mut self) -> Result<i32> {
         let mut buffer: [u8; 4] = [0; 4];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         read_data!(self.endian, buffer, i32);
     }
 
     /// Read a `u16` from the stream.
     pub fn read_u16(&mut self) -> Result<u16> {
         let mut buffer: [u8; 2] = [0; 2];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         read_data!(self.endian, buffer, u16);
     }
 
     /// Read an `i16` from the stream.
     pub fn read_i16(&mut self) -> Result<i16> {
         let mut buffer: [u8; 2] = [0; 2];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         read_data!(self.endian, buffer, i16);
     }
 
     /// Read a `u8` from the stream.
     pub fn read_u8(&mut self) -> Result<u8> {
         let mut buffer: [u8; 1] = [0; 1];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         read_data!(self.endian, buffer, u8);
     }
 
     /// Read an `i8` from the stream.
     pub fn read_i8(&mut self) -> Result<i8> {
         let mut buffer: [u8; 1] = [0; 1];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         read_data!(self.endian, buffer, i8);
     }
 
     /// Read bytes from the stream into a buffer.
     pub fn read_bytes(&mut self, length: usize) -> Result<Vec<u8>> {
         let mut buffer: Vec<u8> = vec![0; length];
-        self.stream.read(&mut buffer)?;
+        self.stream.read_exact(&mut buffer)?;
         Ok(buffer)
     }
 
     /// same as `read_bytes` but at a certain offset
     pub fn read_bytes_at(&mut self, length: usize, position: usize) -> Result<Vec<u8>> {
         let mut buffer: Vec<u8> = vec![0; length];
-        self.stream.seek(position)?;
-        self.stream.read(&mut buffer)?;
+        self.stream.seek(SeekFrom::Start(position))?;
+        self.stream.read_exact(&mut buffer)?;
         Ok(buffer)
     }
 
@@ -234,45 +276,139 @@ impl<'a> BinaryReader<'a> {
         let str = self.read_bytes(len)?;
         Ok(String::from_utf8(str)?)
     }
+
+    /// Seek `n` bytes relative to the current position.
+    pub fn skip(&mut self, n: isize) -> Result<usize> {
+        self.stream.seek(SeekFrom::Current(n))
+    }
+
+    /// Read a `u8` from the current position without advancing the stream.
+    pub fn peek_u8(&mut self) -> Result<u8> {
+        peek_data!(self, read_u8)
+    }
+
+    /// Read an `i8` from the current position without advancing the stream.
+    pub fn peek_i8(&mut self) -> Result<i8> {
+        peek_data!(self, read_i8)
+    }
+
+    /// Read a `u16` from the current position without advancing the stream.
+    pub fn peek_u16(&mut self) -> Result<u16> {
+        peek_data!(self, read_u16)
+    }
+
+    /// Read an `i16` from the current position without advancing the stream.
+    pub fn peek_i16(&mut self) -> Result<i16> {
+        peek_data!(self, read_i16)
+    }
+
+    /// Read a `u32` from the current position without advancing the stream.
+    pub fn peek_u32(&mut self) -> Result<u32> {
+        peek_data!(self, read_u32)
+    }
+
+    /// Read an `i32` from the current position without advancing the stream.
+    pub fn peek_i32(&mut self) -> Result<i32> {
+        peek_data!(self, read_i32)
+    }
+
+    /// Read a `u64` from the current position without advancing the stream.
+    pub fn peek_u64(&mut self) -> Result<u64> {
+        peek_data!(self, read_u64)
+    }
+
+    /// Read an `i64` from the current position without advancing the stream.
+    pub fn peek_i64(&mut self) -> Result<i64> {
+        peek_data!(self, read_i64)
+    }
+
+    /// Read a `usize` from the current position without advancing the stream.
+    pub fn peek_usize(&mut self) -> Result<usize> {
+        peek_data!(self, read_usize)
+    }
+
+    /// Read an `isize` from the current position without advancing the stream.
+    pub fn peek_isize(&mut self) -> Result<isize> {
+        peek_data!(self, read_isize)
+    }
+
+    /// Read a `f32` from the current position without advancing the stream.
+    pub fn peek_f32(&mut self) -> Result<f32> {
+        peek_data!(self, read_f32)
+    }
+
+    /// Read a `f64` from the current position without advancing the stream.
+    pub fn peek_f64(&mut self) -> Result<f64> {
+        peek_data!(self, read_f64)
+    }
+
+    /// Read a `bool` from the current position without advancing the stream.
+    pub fn peek_bool(&mut self) -> Result<bool> {
+        peek_data!(self, read_bool)
+    }
+
+    /// Read `length` bytes from the current position without advancing the stream.
+    pub fn peek_bytes(&mut self, length: usize) -> Result<Vec<u8>> {
+        peek_data!(self, read_bytes, length)
+    }
+
+    /// Returns `true` if the stream has no more bytes left to read.
+    pub fn is_eof(&mut self) -> Result<bool> {
+        Ok(self.remaining()? == 0)
+    }
+
+    /// Returns the number of bytes left to read in the stream.
+    pub fn remaining(&mut self) -> Result<usize> {
+        let position = self.stream.tell()?;
+        let len = self.stream.len()?;
+        Ok(len.saturating_sub(position))
+    }
+
+    /// Reads as many bytes as are available, up to `max`, without erroring at EOF.
+    pub fn read_bytes_some(&mut self, max: usize) -> Result<Vec<u8>> {
+        let length = self.remaining()?.min(max);
+        self.read_bytes(length)
+    }
 }
 
 //
 
 //--        BinaryWriter      --\\
-pub struct BinaryWriter<'a> {
-    pub stream: MemoryStream<'a>,
+pub struct BinaryWriter<S: SeekStream + Write> {
+    pub stream: S,
     endian: Endian,
 }
 
-impl<'a> BinaryWriter<'a> {
-    /// Createa a new BinaryWriter with predefined data
-    pub fn new_stream<'b>(stream: MemoryStream<'b>, endian: Endian) -> Self
-    where
-        'b: 'a,
-    {
-        Self { endian, stream }
-    }
-
+impl<'a> BinaryWriter<MemoryStream<'a>> {
+    /// Createa a new BinaryWriter with a predefined buffer
     pub fn new_vec(stream: &'a mut Vec<u8>, endian: Endian) -> Self {
         Self {
             endian,
             stream: MemoryStream::new_vec(stream),
         }
     }
+}
+
+impl<S: SeekStream + Write> BinaryWriter<S> {
+    /// Createa a new BinaryWriter with predefined data
+    pub fn new_stream(stream: S, endian: Endian) -> Self {
+        Self { endian, stream }
+    }
 
     /// Write a length-prefixed `String` to the stream.
     ///
     /// The length of the `String` is written as a `usize`
     /// unless the `wasm32` feature is enabled
     /// in which case the length is a `u32`.
-    pub fn write_string<S: AsRef<str>>(&mut self, value: S) -> Result<usize> {
+    pub fn write_string<T: AsRef<str>>(&mut self, value: T) -> Result<usize> {
         let bytes = value.as_ref().as_bytes();
         if cfg!(feature = "wasm32") {
             self.write_u32(bytes.len() as u32)?;
         } else {
             self.write_usize(bytes.len())?;
         }
-        Ok(self.stream.write(&bytes.to_vec())?)
+        self.stream.write_all(bytes)?;
+        Ok(bytes.len())
     }
 
     /// Write a character to the stream.
@@ -348,13 +484,17 @@ impl<'a> BinaryWriter<'a> {
 
     /// Write a byte buffer to the stream.
     pub fn write_bytes<B: AsRef<[u8]>>(&mut self, data: B) -> Result<usize> {
-        Ok(self.stream.write(data.as_ref())?)
+        let data = data.as_ref();
+        self.stream.write_all(data)?;
+        Ok(data.len())
     }
 
     /// Writes bytes at a certain position. Does not go back to it's original position!
     pub fn write_bytes_at<B: AsRef<[u8]>>(&mut self, data: B, position: usize) -> Result<usize> {
-        self.stream.seek(position)?;
-        Ok(self.stream.write(data.as_ref())?)
+        self.stream.seek(SeekFrom::Start(position))?;
+        let data = data.as_ref();
+        self.stream.write_all(data)?;
+        Ok(data.len())
     }
 
     /// Writes a bigstring with an optional position
@@ -364,9 +504,11 @@ impl<'a> BinaryWriter<'a> {
         position: Option<usize>,
     ) -> Result<usize> {
         if let Some(pos) = position {
-            self.stream.seek(pos)?;
+            self.stream.seek(SeekFrom::Start(pos))?;
         }
-        Ok(self.stream.write(data.as_ref())?)
+        let data = data.as_ref();
+        self.stream.write_all(data)?;
+        Ok(data.len())
     }
 
     /// Write a byte buffer to the stream.
@@ -384,4 +526,94 @@ impl<'a> BinaryWriter<'a> {
             self.endian = Endian::Big;
         }
     }
+
+    /// Seek `n` bytes relative to the current position.
+    pub fn skip(&mut self, n: isize) -> Result<usize> {
+        self.stream.seek(SeekFrom::Current(n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reader_skip_moves_relative_to_current_position() {
+        let mut data = vec![1, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF, 2, 0, 0, 0];
+        let mut reader = BinaryReader::new_vec(&mut data, Endian::Little);
+
+        assert_eq!(reader.read_u32().unwrap(), 1);
+        reader.skip(4).unwrap();
+        assert_eq!(reader.read_u32().unwrap(), 2);
+        reader.skip(-8).unwrap();
+        assert_eq!(reader.read_u32().unwrap(), 0xFFFFFFFF);
+    }
+
+    #[test]
+    fn peek_u8_does_not_advance_the_position() {
+        let mut data = vec![1, 2, 3];
+        let mut reader = BinaryReader::new_vec(&mut data, Endian::Little);
+
+        assert_eq!(reader.peek_u8().unwrap(), 1);
+        assert_eq!(reader.peek_u8().unwrap(), 1);
+        assert_eq!(reader.read_u8().unwrap(), 1);
+        assert_eq!(reader.read_u8().unwrap(), 2);
+    }
+
+    #[test]
+    fn peek_bytes_does_not_advance_the_position() {
+        let mut data = vec![1, 2, 3, 4];
+        let mut reader = BinaryReader::new_vec(&mut data, Endian::Little);
+
+        assert_eq!(reader.peek_bytes(2).unwrap(), vec![1, 2]);
+        assert_eq!(reader.read_bytes(4).unwrap(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn peek_past_eof_restores_the_position_and_errors() {
+        let mut data = vec![1, 2];
+        let mut reader = BinaryReader::new_vec(&mut data, Endian::Little);
+
+        assert!(reader.peek_bytes(3).is_err());
+        assert_eq!(reader.read_bytes(2).unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn is_eof_and_remaining_track_the_current_position() {
+        let mut data = vec![1, 2, 3];
+        let mut reader = BinaryReader::new_vec(&mut data, Endian::Little);
+
+        assert_eq!(reader.remaining().unwrap(), 3);
+        assert!(!reader.is_eof().unwrap());
+
+        reader.read_u8().unwrap();
+        assert_eq!(reader.remaining().unwrap(), 2);
+        assert!(!reader.is_eof().unwrap());
+
+        reader.read_bytes(2).unwrap();
+        assert_eq!(reader.remaining().unwrap(), 0);
+        assert!(reader.is_eof().unwrap());
+    }
+
+    #[test]
+    fn read_bytes_some_caps_at_what_remains_without_erroring() {
+        let mut data = vec![1, 2, 3];
+        let mut reader = BinaryReader::new_vec(&mut data, Endian::Little);
+
+        assert_eq!(reader.read_bytes_some(16).unwrap(), vec![1, 2, 3]);
+        assert!(reader.is_eof().unwrap());
+        assert_eq!(reader.read_bytes_some(16).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn writer_skip_moves_relative_to_current_position() {
+        let mut writer = BinaryWriter::new_stream(MemoryStream::new(), Endian::Little);
+
+        writer.write_u32(1u32).unwrap();
+        writer.write_bytes_with_value(4, 0).unwrap();
+        writer.skip(-4).unwrap();
+        writer.write_u32(2u32).unwrap();
+
+        assert_eq!(writer.stream.get_buffer(), vec![1, 0, 0, 0, 2, 0, 0, 0]);
+    }
 }
@@ -0,0 +1,251 @@
+use std::io::{Error, ErrorKind, Read, Write};
+
+use crate::{resolve_seek, BinaryError, Result, SeekFrom, SeekStream};
+
+/// Backing storage for a [`MemoryStream`].
+enum Buffer<'a> {
+    /// A fixed-capacity buffer borrowed from the caller. Writes past its end fail
+    /// instead of growing it.
+    Borrowed(&'a mut [u8]),
+    /// An owned buffer that grows to fit whatever is written to it.
+    Owned(Vec<u8>),
+}
+
+pub struct MemoryStream<'a> {
+    buffer: Buffer<'a>,
+    pub position: usize,
+}
+
+impl<'a> MemoryStream<'a> {
+    /// Creates a new, empty stream that owns its buffer and grows as data is written.
+    pub fn new() -> Self {
+        Self {
+            buffer: Buffer::Owned(Vec::new()),
+            position: 0,
+        }
+    }
+
+    /// Creates an owned, growable stream seeded with `buffer`.
+    pub fn from_vec(buffer: Vec<u8>) -> Self {
+        Self {
+            buffer: Buffer::Owned(buffer),
+            position: 0,
+        }
+    }
+
+    /// Creates a stream backed by a borrowed, fixed-capacity buffer. Writing past
+    /// its end fails with an I/O error wrapping [`BinaryError::WritePastEnd`]
+    /// instead of growing it.
+    pub fn new_vec(buffer: &'a mut Vec<u8>) -> Self {
+        Self {
+            buffer: Buffer::Borrowed(buffer.as_mut_slice()),
+            position: 0,
+        }
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        match &self.buffer {
+            Buffer::Borrowed(buffer) => buffer,
+            Buffer::Owned(buffer) => buffer,
+        }
+    }
+
+    pub fn get_buffer(&self) -> Vec<u8> {
+        self.as_slice().to_vec()
+    }
+
+    /// Copies data from the current position into `buffer` without mutating `position`.
+    pub fn peek(&mut self, buffer: &mut [u8]) -> Result<usize> {
+        let slice = self.as_slice();
+        if self.position + buffer.len() > slice.len() {
+            return Err(BinaryError::ReadPastEof);
+        }
+        buffer.copy_from_slice(&slice[self.position..self.position + buffer.len()]);
+        Ok(buffer.len())
+    }
+}
+
+impl<'a> Default for MemoryStream<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// This implements the `SeekStream` trait from lib.rs into `MemoryStream`
+impl<'a> SeekStream for MemoryStream<'a> {
+    /// Moves the position according to `to`, resolving `Current`/`End` against the
+    /// current position and buffer length, and returns the new position.
+    fn seek(&mut self, to: SeekFrom) -> Result<usize> {
+        self.position = resolve_seek(to, self.position, self.as_slice().len())?;
+        Ok(self.position)
+    }
+    /// Returns the current position of a mutable reference.
+    ///
+    /// Returns:
+    ///
+    /// The `tell` function is returning a `Result` enum with a value of `usize`.
+    fn tell(&mut self) -> Result<usize> {
+        Ok(self.position)
+    }
+    /// Returns the length of the buffer as a `Result` containing a `usize`.
+    ///
+    /// Returns:
+    ///
+    /// The `len` function is returning a `Result` type with a value of `usize`.
+    fn len(&self) -> Result<usize> {
+        Ok(self.as_slice().len())
+    }
+}
+
+impl<'a> Read for MemoryStream<'a> {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        let slice = self.as_slice();
+        if self.position + buffer.len() > slice.len() {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                BinaryError::ReadPastEof,
+            ));
+        }
+
+        buffer.copy_from_slice(&slice[self.position..self.position + buffer.len()]);
+        self.position += buffer.len();
+
+        Ok(buffer.len())
+    }
+}
+
+impl<'a> Write for MemoryStream<'a> {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        let end = self.position + bytes.len();
+
+        match &mut self.buffer {
+            Buffer::Owned(buffer) => {
+                if end > buffer.len() {
+                    buffer.resize(end, 0);
+                }
+                buffer[self.position..end].copy_from_slice(bytes);
+            }
+            Buffer::Borrowed(buffer) => {
+                if end > buffer.len() {
+                    return Err(Error::new(ErrorKind::WriteZero, BinaryError::WritePastEnd));
+                }
+                buffer[self.position..end].copy_from_slice(bytes);
+            }
+        }
+
+        self.position = end;
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Into<Vec<u8>> for MemoryStream<'_> {
+    fn into(self) -> Vec<u8> {
+        match self.buffer {
+            Buffer::Borrowed(buffer) => buffer.to_vec(),
+            Buffer::Owned(buffer) => buffer,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seek_start_current_and_end() {
+        let mut data = vec![1, 2, 3, 4, 5];
+        let mut stream = MemoryStream::new_vec(&mut data);
+
+        assert_eq!(stream.seek(SeekFrom::Start(2)).unwrap(), 2);
+        assert_eq!(stream.seek(SeekFrom::Current(2)).unwrap(), 4);
+        assert_eq!(stream.seek(SeekFrom::Current(-3)).unwrap(), 1);
+        assert_eq!(stream.seek(SeekFrom::End(0)).unwrap(), 5);
+        assert_eq!(stream.seek(SeekFrom::End(-2)).unwrap(), 3);
+    }
+
+    #[test]
+    fn seek_out_of_range_errors() {
+        let mut data = vec![1, 2, 3];
+        let mut stream = MemoryStream::new_vec(&mut data);
+
+        assert!(matches!(
+            stream.seek(SeekFrom::Start(10)),
+            Err(BinaryError::SeekOutOfRange)
+        ));
+        assert!(matches!(
+            stream.seek(SeekFrom::Current(-1)),
+            Err(BinaryError::SeekOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn owned_stream_grows_as_data_is_written() {
+        let mut stream = MemoryStream::new();
+
+        stream.write_all(&[1, 2]).unwrap();
+        stream.write_all(&[3, 4, 5]).unwrap();
+
+        assert_eq!(stream.get_buffer(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn owned_stream_write_is_position_aware() {
+        let mut stream = MemoryStream::new();
+
+        stream.write_all(&[1, 2, 3, 4]).unwrap();
+        stream.seek(SeekFrom::Start(1)).unwrap();
+        stream.write_all(&[9, 9]).unwrap();
+
+        assert_eq!(stream.get_buffer(), vec![1, 9, 9, 4]);
+    }
+
+    #[test]
+    fn borrowed_stream_write_past_end_errors_instead_of_panicking() {
+        let mut data = vec![0, 0];
+        let mut stream = MemoryStream::new_vec(&mut data);
+
+        let result = stream.write_all(&[1, 2, 3]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn borrowed_stream_write_within_capacity_succeeds() {
+        let mut data = vec![0, 0, 0];
+        let mut stream = MemoryStream::new_vec(&mut data);
+
+        stream.write_all(&[1, 2]).unwrap();
+
+        assert_eq!(stream.get_buffer(), vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn peek_does_not_advance_the_position() {
+        let mut data = vec![1, 2, 3, 4];
+        let mut stream = MemoryStream::new_vec(&mut data);
+        let mut buffer = [0u8; 2];
+
+        stream.peek(&mut buffer).unwrap();
+        assert_eq!(buffer, [1, 2]);
+        assert_eq!(stream.tell().unwrap(), 0);
+
+        let mut full = [0u8; 4];
+        stream.peek(&mut full).unwrap();
+        assert_eq!(full, [1, 2, 3, 4]);
+        assert_eq!(stream.tell().unwrap(), 0);
+    }
+
+    #[test]
+    fn peek_past_end_errors_without_mutating_position() {
+        let mut data = vec![1, 2];
+        let mut stream = MemoryStream::new_vec(&mut data);
+        let mut buffer = [0u8; 3];
+
+        assert!(stream.peek(&mut buffer).is_err());
+        assert_eq!(stream.tell().unwrap(), 0);
+    }
+}
@@ -0,0 +1,111 @@
+use std::io::Read;
+
+use crate::{resolve_seek, BinaryError, Result, SeekFrom, SeekStream};
+
+/// A read-only [`SeekStream`] over a borrowed, immutable `&[u8]`, so `BinaryReader`
+/// can parse borrowed or memory-mapped data without cloning it into a `Vec`.
+pub struct SliceStream<'a> {
+    buffer: &'a [u8],
+    position: usize,
+}
+
+impl<'a> SliceStream<'a> {
+    /// Creates a new stream over `buffer`.
+    pub fn new(buffer: &'a [u8]) -> Self {
+        Self {
+            buffer,
+            position: 0,
+        }
+    }
+}
+
+/// This implements the `SeekStream` trait from lib.rs into `SliceStream`
+impl<'a> SeekStream for SliceStream<'a> {
+    fn seek(&mut self, to: SeekFrom) -> Result<usize> {
+        self.position = resolve_seek(to, self.position, self.buffer.len())?;
+        Ok(self.position)
+    }
+
+    fn tell(&mut self) -> Result<usize> {
+        Ok(self.position)
+    }
+
+    fn len(&self) -> Result<usize> {
+        Ok(self.buffer.len())
+    }
+}
+
+impl<'a> Read for SliceStream<'a> {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        if self.position + buffer.len() > self.buffer.len() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                BinaryError::ReadPastEof,
+            ));
+        }
+
+        buffer.copy_from_slice(&self.buffer[self.position..self.position + buffer.len()]);
+        self.position += buffer.len();
+
+        Ok(buffer.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BinaryReader, Endian};
+
+    #[test]
+    fn reads_over_a_borrowed_slice() {
+        let data = [1u8, 0, 0, 0];
+        let stream = SliceStream::new(&data);
+        let mut reader = BinaryReader::new_stream(stream, Endian::Little);
+        assert_eq!(reader.read_u32().unwrap(), 1);
+    }
+
+    #[test]
+    fn seek_start_current_and_end() {
+        let data = [1, 2, 3, 4, 5];
+        let mut stream = SliceStream::new(&data);
+
+        assert_eq!(stream.seek(SeekFrom::Start(2)).unwrap(), 2);
+        assert_eq!(stream.seek(SeekFrom::Current(2)).unwrap(), 4);
+        assert_eq!(stream.seek(SeekFrom::Current(-3)).unwrap(), 1);
+        assert_eq!(stream.seek(SeekFrom::End(0)).unwrap(), 5);
+        assert_eq!(stream.seek(SeekFrom::End(-2)).unwrap(), 3);
+    }
+
+    #[test]
+    fn seek_out_of_range_errors() {
+        let data = [1, 2, 3];
+        let mut stream = SliceStream::new(&data);
+
+        assert!(matches!(
+            stream.seek(SeekFrom::Start(10)),
+            Err(BinaryError::SeekOutOfRange)
+        ));
+        assert!(matches!(
+            stream.seek(SeekFrom::Current(-1)),
+            Err(BinaryError::SeekOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn tell_reflects_the_current_position() {
+        let data = [1, 2, 3, 4];
+        let mut stream = SliceStream::new(&data);
+
+        assert_eq!(stream.tell().unwrap(), 0);
+        stream.seek(SeekFrom::Start(3)).unwrap();
+        assert_eq!(stream.tell().unwrap(), 3);
+    }
+
+    #[test]
+    fn len_reflects_the_buffer_size() {
+        let data = [1, 2, 3, 4, 5];
+        let stream = SliceStream::new(&data);
+
+        assert_eq!(stream.len().unwrap(), 5);
+    }
+}